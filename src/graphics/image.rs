@@ -2,6 +2,7 @@ use std::path;
 use std::io::Read;
 
 use gfx;
+use gfx::format::Formatted;
 use gfx_device_gl;
 use image;
 
@@ -11,19 +12,28 @@ use context::Context;
 use GameResult;
 use GameError;
 
-/// Generic in-GPU-memory image data available to be drawn on the screen.
+/// Generic in-GPU-memory image data available to be drawn on the screen,
+/// parameterized over the gfx surface/channel format `F` the texture is
+/// stored as.
+///
+/// Most users just want [`Image`](type.Image.html), which is pinned to
+/// 8-bit sRGBA; `ImageGeneric` also backs the floating-point, integer, and
+/// depth textures described by [`PixelFormat`](enum.PixelFormat.html).
 #[derive(Clone)]
-pub struct ImageGeneric<R>
+pub struct ImageGeneric<R, F = gfx::format::Srgba8>
 where
     R: gfx::Resources,
+    F: Formatted,
 {
     // TODO: Rename to shader_view or such.
-    pub(crate) texture: gfx::handle::ShaderResourceView<R, [f32; 4]>,
-    pub(crate) texture_handle: gfx::handle::Texture<R, gfx::format::R8_G8_B8_A8>,
+    pub(crate) texture: gfx::handle::ShaderResourceView<R, F::View>,
+    pub(crate) texture_handle: gfx::handle::Texture<R, F::Surface>,
     pub(crate) sampler_info: gfx::texture::SamplerInfo,
     pub(crate) blend_mode: Option<BlendMode>,
     pub(crate) width: u32,
     pub(crate) height: u32,
+    pub(crate) format: PixelFormat,
+    pub(crate) mipmap_mode: MipmapMode,
 }
 
 /// In-GPU-memory image data available to be drawn on the screen,
@@ -34,16 +44,136 @@ where
 /// make another copy of the underlying image data.
 pub type Image = ImageGeneric<gfx_device_gl::Resources>;
 
+/// An `Image` backed by 32-bit floating point RGBA data, for HDR
+/// framebuffers or data textures fed to a custom shader.
+pub type FloatImage = ImageGeneric<gfx_device_gl::Resources, gfx::format::Rgba32F>;
+
+/// An `Image` backed by a single 32-bit floating point channel, such as a
+/// heightmap or other single-channel data texture.
+pub type RedImage = ImageGeneric<gfx_device_gl::Resources, (gfx::format::R32, gfx::format::Float)>;
+
+/// An `Image` backed by 8-bit unsigned-integer RGBA data, for texel data
+/// that a shader wants to read back without sRGB decoding or normalization.
+pub type UintImage =
+    ImageGeneric<gfx_device_gl::Resources, (gfx::format::R8_G8_B8_A8, gfx::format::Uint)>;
+
+/// An `Image` backed by a 32-bit floating point depth buffer.
+pub type DepthImage = ImageGeneric<gfx_device_gl::Resources, gfx::format::Depth32F>;
+
+/// An `Image` backed by a single 16-bit unsigned-integer channel, for
+/// `u16` texel data such as a 16-bit-per-sample heightmap.
+pub type R16Image = ImageGeneric<gfx_device_gl::Resources, (gfx::format::R16, gfx::format::Uint)>;
+
+/// Identifies the in-memory layout of an `ImageGeneric`'s texture data.
+///
+/// This mirrors the `F: gfx::format::Formatted` type parameter used to
+/// pick the concrete texture type at compile time; it is kept alongside
+/// it so code that only has an `&Image` (and not its static type) can
+/// still ask what it's looking at, e.g. to decide how to read it back.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// 8-bit RGBA, sRGB-encoded. The default, used by [`Image`](type.Image.html).
+    Rgba8Srgb,
+    /// 32-bit floating point RGBA, used by [`FloatImage`](type.FloatImage.html).
+    Rgba32F,
+    /// A single 32-bit floating point channel, used by [`RedImage`](type.RedImage.html).
+    R32F,
+    /// 8-bit unsigned-integer RGBA, used by [`UintImage`](type.UintImage.html).
+    Rgba8Uint,
+    /// 32-bit floating point depth, used by [`DepthImage`](type.DepthImage.html).
+    Depth32F,
+    /// A single 16-bit unsigned-integer channel, used by [`R16Image`](type.R16Image.html).
+    R16Uint,
+}
+
+/// Maps a `gfx::format::Formatted` type to the `PixelFormat` tag that
+/// describes it, so format-generic code (like `make_raw_typed`) can report
+/// what it actually built instead of guessing or taking the caller's word
+/// for it.
+pub(crate) trait HasPixelFormat {
+    /// The `PixelFormat` that corresponds to `Self`.
+    const PIXEL_FORMAT: PixelFormat;
+}
+
+impl HasPixelFormat for gfx::format::Srgba8 {
+    const PIXEL_FORMAT: PixelFormat = PixelFormat::Rgba8Srgb;
+}
+
+impl HasPixelFormat for gfx::format::Rgba32F {
+    const PIXEL_FORMAT: PixelFormat = PixelFormat::Rgba32F;
+}
+
+impl HasPixelFormat for (gfx::format::R32, gfx::format::Float) {
+    const PIXEL_FORMAT: PixelFormat = PixelFormat::R32F;
+}
+
+impl HasPixelFormat for (gfx::format::R8_G8_B8_A8, gfx::format::Uint) {
+    const PIXEL_FORMAT: PixelFormat = PixelFormat::Rgba8Uint;
+}
+
+impl HasPixelFormat for gfx::format::Depth32F {
+    const PIXEL_FORMAT: PixelFormat = PixelFormat::Depth32F;
+}
+
+impl HasPixelFormat for (gfx::format::R16, gfx::format::Uint) {
+    const PIXEL_FORMAT: PixelFormat = PixelFormat::R16Uint;
+}
+
 /// The supported formats for saving an image.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ImageFormat {
     /// .png image format (defaults to RGBA with 8-bit channels.)
     Png,
+    /// .jpeg image format, with the given quality (0-100).
+    ///
+    /// JPEG has no alpha channel, so the alpha channel of the
+    /// image is dropped when encoding.
+    Jpeg {
+        /// Encoding quality, from 0 to 100.
+        quality: u8,
+    },
+    /// .bmp image format.
+    Bmp,
+    /// .tga image format.
+    Tga,
+}
+
+/// Describes whether an image should be flipped vertically, horizontally,
+/// both, or left as-is when loaded or copied.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Flip {
+    /// Do not flip the image.
+    None,
+    /// Flip the image vertically (top to bottom).
+    Vertical,
+    /// Flip the image horizontally (left to right).
+    Horizontal,
+    /// Flip the image both vertically and horizontally.
+    Both,
+}
+
+/// Controls whether an `Image` generates a full mipmap chain when it's
+/// created.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MipmapMode {
+    /// Only the single mip level that was provided is kept; minifying the
+    /// image will alias rather than smoothly fade to a lower-resolution
+    /// version.
+    None,
+    /// A full chain of mip levels is generated from the provided data,
+    /// allowing `FilterMode::Linear` to do trilinear filtering when the
+    /// image is drawn smaller than its native size.
+    Generate,
 }
 
 impl Image {
-    /// Load a new image from the file at the given path.
-    pub fn new<P: AsRef<path::Path>>(context: &mut Context, path: P) -> GameResult<Image> {
+    /// Reads the file at the given path and decodes it into RGBA8 pixel
+    /// data, returning its width, height, and pixels. Shared by the various
+    /// `new*` constructors that load from a file.
+    fn load_rgba8<P: AsRef<path::Path>>(
+        context: &mut Context,
+        path: P,
+    ) -> GameResult<(u16, u16, Vec<u8>)> {
         let img = {
             let mut buf = Vec::new();
             let mut reader = context.filesystem.open(path)?;
@@ -51,7 +181,32 @@ impl Image {
             image::load_from_memory(&buf)?.to_rgba()
         };
         let (width, height) = img.dimensions();
-        Image::from_rgba8(context, width as u16, height as u16, &img)
+        Ok((width as u16, height as u16, img.into_raw()))
+    }
+
+    /// Load a new image from the file at the given path.
+    pub fn new<P: AsRef<path::Path>>(context: &mut Context, path: P) -> GameResult<Image> {
+        let (width, height, rgba) = Image::load_rgba8(context, path)?;
+        Image::from_rgba8(context, width, height, &rgba)
+    }
+
+    /// Load a new image from the file at the given path, flipping it
+    /// according to `flip` as it is loaded.
+    pub fn new_flipped<P: AsRef<path::Path>>(
+        context: &mut Context,
+        path: P,
+        flip: Flip,
+    ) -> GameResult<Image> {
+        let image = Image::new(context, path)?;
+        image.flipped(context, flip)
+    }
+
+    /// Load a new image from the file at the given path, generating a full
+    /// mipmap chain for it so it can be drawn smaller than its native size
+    /// without aliasing. See [`MipmapMode`](enum.MipmapMode.html).
+    pub fn new_mipmapped<P: AsRef<path::Path>>(context: &mut Context, path: P) -> GameResult<Image> {
+        let (width, height, rgba) = Image::load_rgba8(context, path)?;
+        Image::from_rgba8_with_mipmap(context, width, height, &rgba, MipmapMode::Generate)
     }
 
     /// Creates a new `Image` from the given buffer of `u8` RGBA values.
@@ -61,15 +216,96 @@ impl Image {
         height: u16,
         rgba: &[u8],
     ) -> GameResult<Image> {
+        Image::from_rgba8_with_mipmap(context, width, height, rgba, MipmapMode::None)
+    }
+
+    /// Creates a new `Image` from the given buffer of `u8` RGBA values,
+    /// choosing whether a full mipmap chain is generated for it.
+    pub fn from_rgba8_with_mipmap(
+        context: &mut Context,
+        width: u16,
+        height: u16,
+        rgba: &[u8],
+        mipmap: MipmapMode,
+    ) -> GameResult<Image> {
+        let gfx = &mut context.gfx_context;
         Image::make_raw(
-            &mut context.gfx_context.factory,
-            &context.gfx_context.default_sampler_info,
+            &mut gfx.factory,
+            &mut *gfx.device,
+            &gfx.default_sampler_info,
             width,
             height,
             rgba,
+            mipmap,
         )
     }
 
+    /// Uploads new pixel data into the given sub-rectangle of the image,
+    /// without reallocating the underlying GPU texture.
+    ///
+    /// `rect` is in pixel coordinates relative to the image's top-left
+    /// corner, and `rgba` must contain exactly `rect.w * rect.h * 4` bytes.
+    /// This is much cheaper than rebuilding the `Image` via `from_rgba8`
+    /// when only part of it changes, e.g. animated procedural textures,
+    /// tile-atlas edits, or blitting in video frames.
+    ///
+    /// If the image was created with `MipmapMode::Generate`, its mip chain
+    /// is regenerated from the updated base level afterwards.
+    pub fn update_rgba8(&mut self, ctx: &mut Context, rect: Rect, rgba: &[u8]) -> GameResult<()> {
+        use gfx::traits::FactoryExt;
+
+        if rect.x < 0.0 || rect.y < 0.0 {
+            let msg = format!(
+                "Tried to update a texture region at ({}, {}), but offsets must be >= 0",
+                rect.x, rect.y
+            );
+            return Err(GameError::ResourceLoadError(msg));
+        }
+        if rect.x + rect.w > self.width as f32 || rect.y + rect.h > self.height as f32 {
+            let msg = format!(
+                "Tried to update a {}x{} region at ({}, {}) of a {}x{} texture, which doesn't fit",
+                rect.w, rect.h, rect.x, rect.y, self.width, self.height
+            );
+            return Err(GameError::ResourceLoadError(msg));
+        }
+
+        let expected_bytes = rect.w as usize * rect.h as usize * 4;
+        if expected_bytes != rgba.len() {
+            let msg = format!(
+                "Tried to update a {}x{} region of a texture, but gave {} bytes of data (expected {})",
+                rect.w, rect.h, rgba.len(), expected_bytes
+            );
+            return Err(GameError::ResourceLoadError(msg));
+        }
+
+        let gfx = &mut ctx.gfx_context;
+        let mut local_encoder: gfx::Encoder<
+            gfx_device_gl::Resources,
+            gfx_device_gl::CommandBuffer,
+        > = gfx.factory.create_command_buffer().into();
+
+        local_encoder.update_texture::<gfx::format::R8_G8_B8_A8, gfx::format::Srgba8>(
+            &self.texture_handle,
+            None,
+            gfx::texture::ImageInfoCommon {
+                xoffset: rect.x as u16,
+                yoffset: rect.y as u16,
+                zoffset: 0,
+                width: rect.w as u16,
+                height: rect.h as u16,
+                depth: 0,
+                format: (),
+                mipmap: 0,
+            },
+            rgba,
+        )?;
+        if self.mipmap_mode == MipmapMode::Generate {
+            local_encoder.generate_mipmap(&self.texture);
+        }
+        local_encoder.flush(&mut *gfx.device);
+        Ok(())
+    }
+
     /// Dumps the `Image`'s data to a `Vec` of `u8` RGBA values.
     pub fn to_rgba8(&self, ctx: &mut Context) -> GameResult<Vec<u8>> {
         use gfx::memory::Typed;
@@ -125,6 +361,41 @@ impl Image {
         Ok(data)
     }
 
+    /// Returns a copy of this `Image`, flipped as described by `flip`.
+    ///
+    /// Many image sources (and render-target readbacks, see the row-reversal
+    /// in `to_rgba8`) arrive in the opposite vertical orientation from what
+    /// you want, so this saves having to reorder the bytes by hand.
+    pub fn flipped(&self, ctx: &mut Context, flip: Flip) -> GameResult<Image> {
+        let width = self.width as usize;
+        let height = self.height as usize;
+        let data = self.to_rgba8(ctx)?;
+
+        let flip_vertical = flip == Flip::Vertical || flip == Flip::Both;
+        let flip_horizontal = flip == Flip::Horizontal || flip == Flip::Both;
+
+        // `to_rgba8` hands the rows back in reverse order relative to the
+        // texture, so reversing them again keeps the original orientation;
+        // a vertical flip keeps them in the order `to_rgba8` returned.
+        let mut out = Vec::with_capacity(data.len());
+        let rows: Box<dyn Iterator<Item = &[u8]>> = if flip_vertical {
+            Box::new(data.chunks(width * 4))
+        } else {
+            Box::new(data.chunks(width * 4).rev())
+        };
+        for row in rows {
+            if flip_horizontal {
+                for pixel in row.chunks(4).rev() {
+                    out.extend(pixel);
+                }
+            } else {
+                out.extend(row);
+            }
+        }
+
+        Image::from_rgba8(ctx, width as u16, height as u16, &out)
+    }
+
     /// Encode the `Image` to the given file format and
     /// write it out to the given path.
     ///
@@ -145,19 +416,46 @@ impl Image {
             ImageFormat::Png => image::png::PNGEncoder::new(writer)
                 .encode(&data, self.width, self.height, color_format)
                 .map_err(|e| e.into()),
+            ImageFormat::Jpeg { quality } => {
+                // JPEG doesn't support an alpha channel, so pack the RGB
+                // channels only before handing them to the encoder.
+                let rgb: Vec<u8> = data.chunks(4).flat_map(|px| px[0..3].iter().cloned()).collect();
+                image::jpeg::JPEGEncoder::new_with_quality(writer, quality)
+                    .encode(
+                        &rgb,
+                        self.width,
+                        self.height,
+                        image::ColorType::RGB(8),
+                    )
+                    .map_err(|e| e.into())
+            }
+            ImageFormat::Bmp => image::bmp::BMPEncoder::new(writer)
+                .encode(&data, self.width, self.height, color_format)
+                .map_err(|e| e.into()),
+            ImageFormat::Tga => image::tga::TGAEncoder::new(writer)
+                .encode(&data, self.width, self.height, color_format)
+                .map_err(|e| e.into()),
         }
     }
 
-    /// A helper function that just takes a factory directly so we can make an image
-    /// without needing the full context object, so we can create an Image while still
-    /// creating the GraphicsContext.
+    /// A helper function that just takes a factory and device directly so we
+    /// can make an image without needing the full context object, so we can
+    /// create an Image while still creating the GraphicsContext.
+    ///
+    /// The backing texture is created as a dynamic (rather than immutable)
+    /// texture so that `update_rgba8` can later upload sub-rectangles into
+    /// it without reallocating.
     pub(crate) fn make_raw(
         factory: &mut gfx_device_gl::Factory,
+        device: &mut gfx_device_gl::Device,
         sampler_info: &texture::SamplerInfo,
         width: u16,
         height: u16,
         rgba: &[u8],
+        mipmap: MipmapMode,
     ) -> GameResult<Image> {
+        use gfx::traits::FactoryExt;
+
         if width == 0 || height == 0 {
             let msg = format!(
                 "Tried to create a texture of size {}x{}, each dimension must
@@ -172,12 +470,51 @@ impl Image {
             let msg = format!("Tried to create a texture of size {}x{}, but gave {} bytes of data (expected {})", width, height, rgba.len(), expected_bytes);
             return Err(GameError::ResourceLoadError(msg));
         }
+        // A level count of 0 tells gfx to allocate the full mip chain down
+        // to a 1x1 level; a count of 1 allocates just the base level we
+        // upload below.
+        let levels = match mipmap {
+            MipmapMode::None => 1,
+            MipmapMode::Generate => 0,
+        };
         let kind = gfx::texture::Kind::D2(width, height, gfx::texture::AaMode::Single);
-        let (tex, view) = factory.create_texture_immutable_u8::<gfx::format::Srgba8>(
+        let tex = factory.create_texture::<gfx::format::R8_G8_B8_A8>(
             kind,
-            gfx::texture::Mipmap::Provided,
-            &[rgba],
+            levels,
+            gfx::memory::Bind::SHADER_RESOURCE,
+            gfx::memory::Usage::Dynamic,
+            Some(gfx::format::ChannelType::Srgb),
+        )?;
+        let view = factory.view_texture_as_shader_resource::<gfx::format::Srgba8>(
+            &tex,
+            (0, 0),
+            gfx::format::Swizzle::new(),
+        )?;
+
+        let mut local_encoder: gfx::Encoder<
+            gfx_device_gl::Resources,
+            gfx_device_gl::CommandBuffer,
+        > = factory.create_command_buffer().into();
+        local_encoder.update_texture::<gfx::format::R8_G8_B8_A8, gfx::format::Srgba8>(
+            &tex,
+            None,
+            gfx::texture::ImageInfoCommon {
+                xoffset: 0,
+                yoffset: 0,
+                zoffset: 0,
+                width,
+                height,
+                depth: 0,
+                format: (),
+                mipmap: 0,
+            },
+            rgba,
         )?;
+        if mipmap == MipmapMode::Generate {
+            local_encoder.generate_mipmap(&view);
+        }
+        local_encoder.flush(device);
+
         Ok(Image {
             texture: view,
             texture_handle: tex,
@@ -185,6 +522,8 @@ impl Image {
             blend_mode: None,
             width: u32::from(width),
             height: u32::from(height),
+            format: PixelFormat::Rgba8Srgb,
+            mipmap_mode: mipmap,
         })
     }
 
@@ -202,6 +541,116 @@ impl Image {
         }
         Image::from_rgba8(context, size, size, &buffer)
     }
+}
+
+/// Creates a single-channel floating-point image (e.g.
+/// [`RedImage`](type.RedImage.html)) from raw `f32` texel data, such as a
+/// heightmap or a data texture for a compute-style shader.
+///
+/// This only applies to formats whose texel representation actually is a
+/// bare `f32` -- a packed multi-channel format like
+/// [`FloatImage`](type.FloatImage.html) stores several floats per texel and
+/// so isn't constructible from a flat `&[f32]` this way.
+impl<F> ImageGeneric<gfx_device_gl::Resources, F>
+where
+    F: gfx::format::TextureFormat + HasPixelFormat,
+    F::Surface: gfx::format::SurfaceTyped<DataType = f32>,
+{
+    /// Creates a new `Image` from the given buffer of `f32` texel values.
+    pub fn from_f32(
+        context: &mut Context,
+        width: u16,
+        height: u16,
+        data: &[f32],
+    ) -> GameResult<Self> {
+        Self::make_raw_typed(
+            &mut context.gfx_context.factory,
+            &context.gfx_context.default_sampler_info,
+            width,
+            height,
+            data,
+        )
+    }
+}
+
+/// Creates a single-channel 16-bit-unsigned-integer image (e.g.
+/// [`R16Image`](type.R16Image.html)) from raw `u16` texel data.
+impl<F> ImageGeneric<gfx_device_gl::Resources, F>
+where
+    F: gfx::format::TextureFormat + HasPixelFormat,
+    F::Surface: gfx::format::SurfaceTyped<DataType = u16>,
+{
+    /// Creates a new `Image` from the given buffer of `u16` texel values.
+    pub fn from_u16(
+        context: &mut Context,
+        width: u16,
+        height: u16,
+        data: &[u16],
+    ) -> GameResult<Self> {
+        Self::make_raw_typed(
+            &mut context.gfx_context.factory,
+            &context.gfx_context.default_sampler_info,
+            width,
+            height,
+            data,
+        )
+    }
+}
+
+impl<R, F> ImageGeneric<R, F>
+where
+    R: gfx::Resources,
+    F: Formatted,
+{
+    /// A helper function, analogous to `Image::make_raw`, that builds a
+    /// texture from texel data in its native (non-`u8`) representation --
+    /// used for the floating-point and integer pixel formats. The stored
+    /// `PixelFormat` tag is derived from `F` itself via `HasPixelFormat`,
+    /// rather than trusted from the caller.
+    pub(crate) fn make_raw_typed<T>(
+        factory: &mut gfx_device_gl::Factory,
+        sampler_info: &texture::SamplerInfo,
+        width: u16,
+        height: u16,
+        data: &[T],
+    ) -> GameResult<Self>
+    where
+        F: gfx::format::TextureFormat + HasPixelFormat,
+        F::Surface: gfx::format::SurfaceTyped<DataType = T>,
+    {
+        if width == 0 || height == 0 {
+            let msg = format!(
+                "Tried to create a texture of size {}x{}, each dimension must
+                be >0",
+                width, height
+            );
+            return Err(GameError::ResourceLoadError(msg));
+        }
+        let expected_texels = width as usize * height as usize;
+        if expected_texels != data.len() {
+            let msg = format!(
+                "Tried to create a texture of size {}x{}, but gave {} texels of data (expected {})",
+                width, height, data.len(), expected_texels
+            );
+            return Err(GameError::ResourceLoadError(msg));
+        }
+        let kind = gfx::texture::Kind::D2(width, height, gfx::texture::AaMode::Single);
+        let (tex, view) = factory.create_texture_immutable::<F>(
+            kind,
+            gfx::texture::Mipmap::Provided,
+            &[data],
+        )?;
+        Ok(ImageGeneric {
+            texture: view,
+            texture_handle: tex,
+            sampler_info: *sampler_info,
+            blend_mode: None,
+            width: u32::from(width),
+            height: u32::from(height),
+            format: F::PIXEL_FORMAT,
+            mipmap_mode: MipmapMode::None,
+        })
+    }
 
     /// Return the width of the image.
     pub fn width(&self) -> u32 {
@@ -213,12 +662,28 @@ impl Image {
         self.height
     }
 
+    /// Returns which [`PixelFormat`](enum.PixelFormat.html) the image's
+    /// texture data is stored as.
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// Returns whether this image has a mipmap chain generated for it; see
+    /// [`MipmapMode`](enum.MipmapMode.html).
+    pub fn mipmap_mode(&self) -> MipmapMode {
+        self.mipmap_mode
+    }
+
     /// Get the filter mode for the image.
     pub fn get_filter(&self) -> FilterMode {
         self.sampler_info.filter.into()
     }
 
     /// Set the filter mode for the image.
+    ///
+    /// `FilterMode::Linear` only does trilinear filtering between mip
+    /// levels if the image was created with `MipmapMode::Generate`;
+    /// otherwise minification behaves as if only one mip level exists.
     pub fn set_filter(&mut self, mode: FilterMode) {
         self.sampler_info.filter = mode.into();
     }
@@ -240,6 +705,61 @@ impl Image {
     }
 }
 
+impl<F> ImageGeneric<gfx_device_gl::Resources, F>
+where
+    F: Formatted,
+{
+    /// Dumps the image's data to a `Vec` in its native texel representation.
+    ///
+    /// This is the format-aware counterpart to `Image::to_rgba8`: rather
+    /// than assuming 8-bit RGBA, it reads back whatever `F`'s surface type
+    /// actually stores, so it works for `FloatImage`, `RedImage`,
+    /// `UintImage`, and `DepthImage` alike.
+    pub fn to_raw(
+        &self,
+        ctx: &mut Context,
+    ) -> GameResult<Vec<<F::Surface as gfx::format::SurfaceTyped>::DataType>>
+    where
+        <F::Surface as gfx::format::SurfaceTyped>::DataType: Copy,
+    {
+        use gfx::memory::Typed;
+        use gfx::format::SurfaceTyped;
+        use gfx::traits::FactoryExt;
+
+        type SurfaceData<F> = <<F as Formatted>::Surface as SurfaceTyped>::DataType;
+
+        let gfx = &mut ctx.gfx_context;
+        let dl_buffer = gfx.factory
+            .create_download_buffer::<SurfaceData<F>>(self.width as usize * self.height as usize)?;
+
+        let mut local_encoder: gfx::Encoder<
+            gfx_device_gl::Resources,
+            gfx_device_gl::CommandBuffer,
+        > = gfx.factory.create_command_buffer().into();
+
+        local_encoder.copy_texture_to_buffer_raw(
+            self.texture_handle.raw(),
+            None,
+            gfx::texture::RawImageInfo {
+                xoffset: 0,
+                yoffset: 0,
+                zoffset: 0,
+                width: self.width as u16,
+                height: self.height as u16,
+                depth: 0,
+                format: F::get_format(),
+                mipmap: 0,
+            },
+            dl_buffer.raw(),
+            0,
+        )?;
+        local_encoder.flush(&mut *gfx.device);
+
+        let reader = gfx.factory.read_mapping(&dl_buffer)?;
+        Ok(reader.iter().cloned().collect())
+    }
+}
+
 impl fmt::Debug for Image {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(